@@ -12,7 +12,7 @@ use std::collections::HashMap;
 use std::env;
 use tokio;
 use anyhow::{Result, Context};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Timelike, Utc};
 
 #[derive(Debug, Deserialize)]
 struct CalendarListArgs {
@@ -25,6 +25,8 @@ struct EventsListArgs {
     time_max: Option<String>,
     time_min: Option<String>,
     verbose: Option<bool>,
+    expand_recurrences: Option<bool>,
+    max_results: Option<i64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -40,6 +42,104 @@ struct FreeBusyArgs {
     calendar_ids: Option<Vec<String>>,
 }
 
+#[derive(Debug, Deserialize)]
+struct CreateEventArgs {
+    calendar_id: Option<String>,
+    summary: String,
+    description: Option<String>,
+    location: Option<String>,
+    start: EventDateTime,
+    end: EventDateTime,
+    attendees: Option<Vec<EventAttendee>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateEventArgs {
+    calendar_id: Option<String>,
+    event_id: String,
+    summary: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    start: Option<EventDateTime>,
+    end: Option<EventDateTime>,
+    attendees: Option<Vec<EventAttendee>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeleteEventArgs {
+    calendar_id: Option<String>,
+    event_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListAclArgs {
+    calendar_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ShareCalendarArgs {
+    calendar_id: Option<String>,
+    email: String,
+    role: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FindSlotsArgs {
+    time_min: String,
+    time_max: String,
+    calendar_ids: Option<Vec<String>>,
+    duration_minutes: i64,
+    working_hours_start: Option<String>,
+    working_hours_end: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExportIcsArgs {
+    calendar_id: Option<String>,
+    time_min: Option<String>,
+    time_max: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ImportIcsArgs {
+    calendar_id: Option<String>,
+    ics_content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct WatchCalendarArgs {
+    calendar_id: Option<String>,
+    address: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct StopWatchArgs {
+    calendar_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SyncChangesArgs {
+    calendar_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AclRule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<AclScope>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AclScope {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    scope_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct Calendar {
     id: Option<String>,
@@ -51,34 +151,53 @@ struct Calendar {
 
 #[derive(Debug, Serialize, Deserialize)]
 struct CalendarEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
     id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     start: Option<EventDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     end: Option<EventDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     creator: Option<EventPerson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     organizer: Option<EventPerson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     attendees: Option<Vec<EventAttendee>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence: Option<Vec<String>>,
+    #[serde(rename = "recurringEventId", skip_serializing_if = "Option::is_none")]
+    recurring_event_id: Option<String>,
+    #[serde(rename = "originalStartTime", skip_serializing_if = "Option::is_none")]
+    original_start_time: Option<EventDateTime>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct EventDateTime {
-    #[serde(rename = "dateTime")]
+    #[serde(rename = "dateTime", skip_serializing_if = "Option::is_none")]
     date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     date: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct EventPerson {
+    #[serde(skip_serializing_if = "Option::is_none")]
     email: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct EventAttendee {
+    #[serde(skip_serializing_if = "Option::is_none")]
     email: Option<String>,
-    #[serde(rename = "responseStatus")]
+    #[serde(rename = "responseStatus", skip_serializing_if = "Option::is_none")]
     response_status: Option<String>,
 }
 
@@ -106,10 +225,627 @@ struct FreeBusyRequestItem {
     id: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct CalendarListPage {
+    items: Option<Vec<Calendar>>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventsPage {
+    items: Option<Vec<Value>>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+}
+
+const DEFAULT_PAGE_SIZE: i64 = 250;
+const MAX_PAGE_FETCHES: usize = 20;
+const MAX_TOTAL_EVENTS: usize = 1000;
+
+// Recurrence expansion: materializes RRULE-bearing events into concrete
+// occurrences so the LLM sees every slot a recurring event actually occupies
+// instead of a single row with a raw RRULE string.
+const RECURRENCE_LOOKBACK_DAYS: i64 = 30;
+const RECURRENCE_LOOKAHEAD_DAYS: i64 = 366;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RecurrenceFreq {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone)]
+struct RecurrenceRule {
+    freq: RecurrenceFreq,
+    interval: i64,
+    count: Option<i64>,
+    until: Option<DateTime<Utc>>,
+    by_day: Vec<chrono::Weekday>,
+    by_month_day: Vec<i64>,
+}
+
+fn parse_ical_datetime(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    // Bare RRULE/EXDATE values look like `20240115T090000Z` or `20240115`.
+    if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), "%Y%m%dT%H%M%S") {
+        return Some(DateTime::from_naive_utc_and_offset(dt, Utc));
+    }
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y%m%d") {
+        return Some(DateTime::from_naive_utc_and_offset(date.and_hms_opt(0, 0, 0)?, Utc));
+    }
+    None
+}
+
+fn parse_weekday(code: &str) -> Option<chrono::Weekday> {
+    match code {
+        "MO" => Some(chrono::Weekday::Mon),
+        "TU" => Some(chrono::Weekday::Tue),
+        "WE" => Some(chrono::Weekday::Wed),
+        "TH" => Some(chrono::Weekday::Thu),
+        "FR" => Some(chrono::Weekday::Fri),
+        "SA" => Some(chrono::Weekday::Sat),
+        "SU" => Some(chrono::Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn parse_rrule(rule: &str) -> Option<RecurrenceRule> {
+    let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+
+    let mut freq = None;
+    let mut interval = 1i64;
+    let mut count = None;
+    let mut until = None;
+    let mut by_day = vec![];
+    let mut by_month_day = vec![];
+
+    for part in rule.split(';') {
+        let mut kv = part.splitn(2, '=');
+        let key = kv.next()?.trim();
+        let val = kv.next()?.trim();
+
+        match key {
+            "FREQ" => {
+                freq = match val {
+                    "DAILY" => Some(RecurrenceFreq::Daily),
+                    "WEEKLY" => Some(RecurrenceFreq::Weekly),
+                    "MONTHLY" => Some(RecurrenceFreq::Monthly),
+                    "YEARLY" => Some(RecurrenceFreq::Yearly),
+                    _ => None,
+                };
+            }
+            "INTERVAL" => interval = val.parse().unwrap_or(1),
+            "COUNT" => count = val.parse().ok(),
+            "UNTIL" => until = parse_ical_datetime(val),
+            "BYDAY" => {
+                by_day = val.split(',').filter_map(parse_weekday).collect();
+            }
+            "BYMONTHDAY" => {
+                by_month_day = val.split(',').filter_map(|d| d.parse().ok()).collect();
+            }
+            _ => {}
+        }
+    }
+
+    Some(RecurrenceRule {
+        freq: freq?,
+        interval: interval.max(1),
+        count,
+        until,
+        by_day,
+        by_month_day,
+    })
+}
+
+/// Generates every occurrence start for `rule` anchored on `dtstart`, stopping
+/// at COUNT/UNTIL or once `cap_end` is reached so an unbounded rule can't spin
+/// forever.
+fn generate_occurrences(rule: &RecurrenceRule, dtstart: DateTime<Utc>, cap_end: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+    let mut occurrences = vec![];
+    let mut emitted = 0i64;
+
+    let within_limits = |when: DateTime<Utc>, emitted: i64| -> bool {
+        if let Some(count) = rule.count {
+            if emitted >= count {
+                return false;
+            }
+        }
+        if let Some(until) = rule.until {
+            if when > until {
+                return false;
+            }
+        }
+        when <= cap_end
+    };
+
+    match rule.freq {
+        RecurrenceFreq::Daily => {
+            let mut current = dtstart;
+            while within_limits(current, emitted) {
+                occurrences.push(current);
+                emitted += 1;
+                current += chrono::Duration::days(rule.interval);
+            }
+        }
+        RecurrenceFreq::Weekly if !rule.by_day.is_empty() => {
+            let mut week_start = dtstart - chrono::Duration::days(dtstart.weekday().num_days_from_monday() as i64);
+            'weeks: loop {
+                // `week_start` carries dtstart's time-of-day, so each weekday
+                // offset lands at the right instant without extra arithmetic.
+                let mut this_week: Vec<DateTime<Utc>> = rule.by_day.iter().map(|wd| {
+                    let offset = wd.num_days_from_monday() as i64;
+                    week_start + chrono::Duration::days(offset)
+                }).collect();
+                this_week.sort();
+
+                for when in this_week {
+                    if when < dtstart {
+                        continue;
+                    }
+                    if !within_limits(when, emitted) {
+                        if when > cap_end || (rule.until.is_some() && when > rule.until.unwrap()) {
+                            break 'weeks;
+                        }
+                        continue;
+                    }
+                    occurrences.push(when);
+                    emitted += 1;
+                }
+
+                if week_start > cap_end {
+                    break;
+                }
+                week_start += chrono::Duration::weeks(rule.interval);
+            }
+        }
+        RecurrenceFreq::Weekly => {
+            let mut current = dtstart;
+            while within_limits(current, emitted) {
+                occurrences.push(current);
+                emitted += 1;
+                current += chrono::Duration::weeks(rule.interval);
+            }
+        }
+        RecurrenceFreq::Monthly => {
+            let days = if rule.by_month_day.is_empty() {
+                vec![dtstart.day() as i64]
+            } else {
+                rule.by_month_day.clone()
+            };
+            let mut month_cursor = dtstart;
+            loop {
+                let mut this_month: Vec<DateTime<Utc>> = days.iter().filter_map(|&day| {
+                    chrono::NaiveDate::from_ymd_opt(month_cursor.year(), month_cursor.month(), day.max(1) as u32)
+                        .and_then(|d| d.and_hms_opt(dtstart.hour(), dtstart.minute(), dtstart.second()))
+                        .map(|n| DateTime::<Utc>::from_naive_utc_and_offset(n, Utc))
+                }).collect();
+                this_month.sort();
+
+                let mut hit_limit = false;
+                for when in this_month.drain(..) {
+                    if when < dtstart {
+                        continue;
+                    }
+                    if !within_limits(when, emitted) {
+                        hit_limit = true;
+                        continue;
+                    }
+                    occurrences.push(when);
+                    emitted += 1;
+                }
+                if hit_limit || month_cursor > cap_end {
+                    break;
+                }
+
+                let total_months = (month_cursor.year() as i64) * 12 + month_cursor.month() as i64 - 1 + rule.interval;
+                let next_year = (total_months / 12) as i32;
+                let next_month = (total_months % 12) as u32 + 1;
+                month_cursor = chrono::NaiveDate::from_ymd_opt(next_year, next_month, 1)
+                    .and_then(|d| d.and_hms_opt(0, 0, 0))
+                    .map(|n| DateTime::<Utc>::from_naive_utc_and_offset(n, Utc))
+                    .unwrap_or(cap_end + chrono::Duration::days(1));
+            }
+        }
+        RecurrenceFreq::Yearly => {
+            let mut current = dtstart;
+            while within_limits(current, emitted) {
+                occurrences.push(current);
+                emitted += 1;
+                current = chrono::NaiveDate::from_ymd_opt(current.year() + rule.interval as i32, current.month(), current.day())
+                    .and_then(|d| d.and_hms_opt(current.hour(), current.minute(), current.second()))
+                    .map(|n| DateTime::<Utc>::from_naive_utc_and_offset(n, Utc))
+                    .unwrap_or(cap_end + chrono::Duration::days(1));
+            }
+        }
+    }
+
+    occurrences
+}
+
+/// Expands every recurring event in `items` into its concrete occurrences
+/// within `time_min`..`time_max`, merges in modified single-instance
+/// overrides, and drops cancelled/EXDATE'd instances. Non-recurring events
+/// pass through untouched. The result is sorted by start time.
+fn expand_recurring_events(items: &[Value], time_min: Option<&str>, time_max: Option<&str>) -> Vec<Value> {
+    let window_min = time_min.and_then(parse_ical_datetime).or_else(|| time_min.and_then(|s| DateTime::parse_from_rfc3339(s).ok().map(|d| d.with_timezone(&Utc))));
+    let window_max = time_max.and_then(parse_ical_datetime).or_else(|| time_max.and_then(|s| DateTime::parse_from_rfc3339(s).ok().map(|d| d.with_timezone(&Utc))));
+
+    let mut masters = vec![];
+    let mut overrides: HashMap<String, Vec<&Value>> = HashMap::new();
+    let mut singles = vec![];
+
+    for item in items {
+        if let Some(master_id) = item.get("recurringEventId").and_then(|v| v.as_str()) {
+            overrides.entry(master_id.to_string()).or_default().push(item);
+        } else if item.get("recurrence").and_then(|v| v.as_array()).map(|a| !a.is_empty()).unwrap_or(false) {
+            masters.push(item);
+        } else {
+            singles.push(item.clone());
+        }
+    }
+
+    let mut expanded = singles;
+
+    for master in masters {
+        let Some(dtstart) = master.get("start").and_then(parse_event_datetime_value) else {
+            continue;
+        };
+        let Some(dtend) = master.get("end").and_then(parse_event_datetime_value) else {
+            continue;
+        };
+        let duration = dtend - dtstart;
+        let is_all_day = master.get("start").and_then(|s| s.get("date")).is_some();
+
+        let recurrence_lines: Vec<&str> = master.get("recurrence")
+            .and_then(|v| v.as_array())
+            .map(|a| a.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+
+        let Some(rule) = recurrence_lines.iter().find_map(|line| {
+            if line.starts_with("RRULE") { parse_rrule(line) } else { None }
+        }) else {
+            expanded.push((*master).clone());
+            continue;
+        };
+
+        let mut exdates = std::collections::HashSet::new();
+        for line in &recurrence_lines {
+            if let Some(rest) = line.strip_prefix("EXDATE") {
+                if let Some(values) = rest.split(':').nth(1) {
+                    for part in values.split(',') {
+                        if let Some(dt) = parse_ical_datetime(part) {
+                            exdates.insert(dt);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Bound the expansion around the requested window (or DTSTART when no
+        // time_min is given) so long-running series still reach the window.
+        let anchor = window_min.map_or(dtstart, |min| min.max(dtstart));
+        let cap_start = anchor - chrono::Duration::days(RECURRENCE_LOOKBACK_DAYS);
+        let cap_end = anchor + chrono::Duration::days(RECURRENCE_LOOKAHEAD_DAYS);
+        let out_min = window_min.unwrap_or(cap_start);
+        let out_max = window_max.unwrap_or(cap_end).min(cap_end);
+
+        let master_id = master.get("id").and_then(|v| v.as_str()).unwrap_or("").to_string();
+        let instance_overrides = overrides.get(&master_id);
+
+        for occurrence_start in generate_occurrences(&rule, dtstart, cap_end) {
+            if occurrence_start < out_min || occurrence_start > out_max {
+                continue;
+            }
+            if exdates.contains(&occurrence_start) {
+                continue;
+            }
+
+            // A modified/cancelled single-instance override replaces this slot.
+            if let Some(overrides_for_master) = instance_overrides {
+                let replaced = overrides_for_master.iter().find(|o| {
+                    o.get("originalStartTime")
+                        .and_then(parse_event_datetime_value)
+                        .map(|orig| orig == occurrence_start)
+                        .unwrap_or(false)
+                });
+                if let Some(replacement) = replaced {
+                    if replacement.get("status").and_then(|v| v.as_str()) != Some("cancelled") {
+                        expanded.push((*replacement).clone());
+                    }
+                    continue;
+                }
+            }
+
+            let occurrence_end = occurrence_start + duration;
+            let mut instance = (*master).clone();
+            if let Some(obj) = instance.as_object_mut() {
+                obj.insert("start".to_string(), event_datetime_json(occurrence_start, is_all_day));
+                obj.insert("end".to_string(), event_datetime_json(occurrence_end, is_all_day));
+            }
+            expanded.push(instance);
+        }
+    }
+
+    expanded.sort_by_key(|event| {
+        event.get("start")
+            .and_then(parse_event_datetime_value)
+            .unwrap_or_else(Utc::now)
+    });
+
+    expanded
+}
+
+fn parse_event_datetime_value(value: &Value) -> Option<DateTime<Utc>> {
+    if let Some(date_time) = value.get("dateTime").and_then(|v| v.as_str()) {
+        return DateTime::parse_from_rfc3339(date_time).ok().map(|d| d.with_timezone(&Utc));
+    }
+    if let Some(date) = value.get("date").and_then(|v| v.as_str()) {
+        return chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|n| DateTime::from_naive_utc_and_offset(n, Utc));
+    }
+    None
+}
+
+/// Sorts and coalesces overlapping/adjacent busy intervals into the minimal
+/// set of disjoint periods.
+fn merge_intervals(mut intervals: Vec<(DateTime<Utc>, DateTime<Utc>)>) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    intervals.sort_by_key(|&(start, _)| start);
+
+    let mut merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = vec![];
+    for (start, end) in intervals {
+        if let Some(last) = merged.last_mut() {
+            if start <= last.1 {
+                last.1 = last.1.max(end);
+                continue;
+            }
+        }
+        merged.push((start, end));
+    }
+    merged
+}
+
+fn parse_hhmm(value: &str) -> Option<(u32, u32)> {
+    let mut parts = value.splitn(2, ':');
+    let hour = parts.next()?.parse().ok()?;
+    let minute = parts.next().unwrap_or("0").parse().ok()?;
+    Some((hour, minute))
+}
+
+fn event_datetime_json(when: DateTime<Utc>, is_all_day: bool) -> Value {
+    if is_all_day {
+        json!({ "date": when.format("%Y-%m-%d").to_string() })
+    } else {
+        json!({ "dateTime": when.to_rfc3339() })
+    }
+}
+
+// iCalendar (RFC 5545) import/export: VCALENDAR/VEVENT rendering and parsing
+// so events can move between this server and the wider CalDAV/ICS ecosystem.
+
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn unescape_ics_text(value: &str) -> String {
+    value.replace("\\n", "\n").replace("\\,", ",").replace("\\;", ";").replace("\\\\", "\\")
+}
+
+fn response_status_to_partstat(status: &str) -> &'static str {
+    match status {
+        "accepted" => "ACCEPTED",
+        "declined" => "DECLINED",
+        "tentative" => "TENTATIVE",
+        _ => "NEEDS-ACTION",
+    }
+}
+
+/// Renders a DTSTART/DTEND property value, distinguishing all-day `date`
+/// events from timed `dateTime` events per RFC 5545 ¶3.6.1.
+fn ics_datetime_value(value: &Value) -> String {
+    if let Some(date) = value.get("date").and_then(|v| v.as_str()) {
+        format!(";VALUE=DATE:{}", date.replace('-', ""))
+    } else if let Some(date_time) = value.get("dateTime").and_then(|v| v.as_str()) {
+        let when = DateTime::parse_from_rfc3339(date_time)
+            .map(|d| d.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+        format!(":{}", when.format("%Y%m%dT%H%M%SZ"))
+    } else {
+        format!(":{}", Utc::now().format("%Y%m%dT%H%M%SZ"))
+    }
+}
+
+/// Renders Calendar API event JSON as a VCALENDAR stream.
+fn render_ics(events: &[Value]) -> String {
+    let mut out = String::new();
+    out.push_str("BEGIN:VCALENDAR\r\n");
+    out.push_str("VERSION:2.0\r\n");
+    out.push_str("PRODID:-//google-calendar-v1-rust//EN\r\n");
+
+    let dtstamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+    for (idx, event) in events.iter().enumerate() {
+        out.push_str("BEGIN:VEVENT\r\n");
+
+        let uid = event.get("id").and_then(|v| v.as_str())
+            .map(|id| format!("{}@google-calendar-v1", id))
+            .unwrap_or_else(|| format!("event-{}@google-calendar-v1", idx));
+        out.push_str(&format!("UID:{}\r\n", uid));
+        out.push_str(&format!("DTSTAMP:{}\r\n", dtstamp));
+
+        if let Some(start) = event.get("start") {
+            out.push_str(&format!("DTSTART{}\r\n", ics_datetime_value(start)));
+        }
+        if let Some(end) = event.get("end") {
+            out.push_str(&format!("DTEND{}\r\n", ics_datetime_value(end)));
+        }
+        if let Some(summary) = event.get("summary").and_then(|v| v.as_str()) {
+            out.push_str(&format!("SUMMARY:{}\r\n", escape_ics_text(summary)));
+        }
+        if let Some(location) = event.get("location").and_then(|v| v.as_str()) {
+            out.push_str(&format!("LOCATION:{}\r\n", escape_ics_text(location)));
+        }
+        if let Some(recurrence) = event.get("recurrence").and_then(|v| v.as_array()) {
+            for line in recurrence.iter().filter_map(|v| v.as_str()) {
+                out.push_str(&format!("{}\r\n", line));
+            }
+        }
+        if let Some(attendees) = event.get("attendees").and_then(|v| v.as_array()) {
+            for attendee in attendees {
+                let email = attendee.get("email").and_then(|v| v.as_str()).unwrap_or("");
+                let partstat = response_status_to_partstat(
+                    attendee.get("responseStatus").and_then(|v| v.as_str()).unwrap_or("needsAction"),
+                );
+                out.push_str(&format!("ATTENDEE;PARTSTAT={}:mailto:{}\r\n", partstat, email));
+            }
+        }
+
+        out.push_str("END:VEVENT\r\n");
+    }
+
+    out.push_str("END:VCALENDAR\r\n");
+    out
+}
+
+/// Unfolds RFC 5545 ¶3.1 continuation lines (a line starting with a space or
+/// tab is a continuation of the previous one).
+fn unfold_ics_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    for raw_line in content.lines() {
+        let line = raw_line.trim_end_matches('\r');
+        if (line.starts_with(' ') || line.starts_with('\t')) && !lines.is_empty() {
+            lines.last_mut().unwrap().push_str(&line[1..]);
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+    lines
+}
+
+fn ics_value_to_event_datetime(value: &str, is_date_value: bool) -> Option<EventDateTime> {
+    if is_date_value || (value.len() == 8 && !value.contains('T')) {
+        if value.len() < 8 || !value.is_char_boundary(4) || !value.is_char_boundary(6) {
+            return None;
+        }
+        let date = format!("{}-{}-{}", &value[0..4], &value[4..6], &value[6..8]);
+        Some(EventDateTime { date_time: None, date: Some(date) })
+    } else {
+        let when = parse_ical_datetime(value).unwrap_or_else(Utc::now);
+        Some(EventDateTime { date_time: Some(when.to_rfc3339()), date: None })
+    }
+}
+
+/// Parses a pasted ICS blob into `CalendarEvent` values ready to insert via
+/// the Calendar API.
+fn parse_ics_events(content: &str) -> Vec<CalendarEvent> {
+    let lines = unfold_ics_lines(content);
+    let mut events = vec![];
+    let mut in_event = false;
+
+    let mut summary = None;
+    let mut description = None;
+    let mut location = None;
+    let mut start = None;
+    let mut end = None;
+    let mut attendees: Vec<EventAttendee> = vec![];
+
+    for line in &lines {
+        if line == "BEGIN:VEVENT" {
+            in_event = true;
+            summary = None;
+            description = None;
+            location = None;
+            start = None;
+            end = None;
+            attendees = vec![];
+            continue;
+        }
+        if line == "END:VEVENT" {
+            in_event = false;
+            if let (Some(start), Some(end)) = (start.take(), end.take()) {
+                events.push(CalendarEvent {
+                    id: None,
+                    summary: summary.take(),
+                    description: description.take(),
+                    start: Some(start),
+                    end: Some(end),
+                    status: None,
+                    creator: None,
+                    organizer: None,
+                    attendees: if attendees.is_empty() { None } else { Some(std::mem::take(&mut attendees)) },
+                    location: location.take(),
+                    recurrence: None,
+                    recurring_event_id: None,
+                    original_start_time: None,
+                });
+            }
+            continue;
+        }
+        if !in_event {
+            continue;
+        }
+
+        let Some((name_and_params, value)) = line.split_once(':') else { continue };
+        let name = name_and_params.split(';').next().unwrap_or("");
+        let is_date_value = name_and_params.contains("VALUE=DATE");
+
+        match name {
+            "SUMMARY" => summary = Some(unescape_ics_text(value)),
+            "DESCRIPTION" => description = Some(unescape_ics_text(value)),
+            "LOCATION" => location = Some(unescape_ics_text(value)),
+            "DTSTART" => start = ics_value_to_event_datetime(value, is_date_value),
+            "DTEND" => end = ics_value_to_event_datetime(value, is_date_value),
+            "ATTENDEE" => {
+                let email = value.strip_prefix("mailto:").unwrap_or(value).to_string();
+                attendees.push(EventAttendee { email: Some(email), response_status: None });
+            }
+            _ => {}
+        }
+    }
+
+    events
+}
+
+// A cached response plus the validators needed to make a conditional
+// (If-None-Match / If-Modified-Since) request on the next call.
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    body: Value,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    cached_at: DateTime<Utc>,
+}
+
+const DEFAULT_CACHE_TTL_SECONDS: u64 = 300;
+const DEFAULT_CACHE_MAX_ENTRIES: usize = 100;
+
+// A registered push-notification channel (events.watch), keyed by calendar ID
+// so stop_watch can find the channel for a calendar.
+#[derive(Debug, Clone)]
+struct WatchChannel {
+    channel_id: String,
+    resource_id: String,
+    expiration: Option<String>,
+}
+
 pub struct GoogleCalendarV1MCP {
     server: Server,
     client: Client,
     base_url: String,
+    cache: std::sync::Mutex<HashMap<String, CacheEntry>>,
+    cache_ttl_seconds: u64,
+    cache_max_entries: usize,
+    watch_channels: std::sync::Mutex<HashMap<String, WatchChannel>>,
+    sync_tokens: std::sync::Mutex<HashMap<String, String>>,
 }
 
 impl GoogleCalendarV1MCP {
@@ -123,13 +859,93 @@ impl GoogleCalendarV1MCP {
 
         let client = Client::new();
 
+        let cache_ttl_seconds = env::var("GOOGLE_CALENDAR_CACHE_TTL_SECONDS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_CACHE_TTL_SECONDS);
+        let cache_max_entries = env::var("GOOGLE_CALENDAR_CACHE_MAX_ENTRIES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_CACHE_MAX_ENTRIES);
+
         Ok(Self {
             server,
             client,
             base_url,
+            cache: std::sync::Mutex::new(HashMap::new()),
+            cache_ttl_seconds,
+            cache_max_entries,
+            watch_channels: std::sync::Mutex::new(HashMap::new()),
+            sync_tokens: std::sync::Mutex::new(HashMap::new()),
         })
     }
 
+    /// Builds a stable cache key from the URL and its (sorted) query params.
+    fn cache_key(url: &str, params: &[(&str, &str)]) -> String {
+        let mut sorted = params.to_vec();
+        sorted.sort();
+        let mut key = url.to_string();
+        for (k, v) in sorted {
+            key.push_str(&format!("|{}={}", k, v));
+        }
+        key
+    }
+
+    /// Issues a GET with conditional-request headers when a fresh cache entry
+    /// exists; on `304 Not Modified` returns the cached body, otherwise stores
+    /// the new body/validators and returns it.
+    async fn get_with_cache(&self, url: &str, params: &[(&str, &str)], auth_header: &str) -> Result<Value> {
+        let key = Self::cache_key(url, params);
+        let now = Utc::now();
+
+        let cached = self.cache.lock().unwrap().get(&key).cloned();
+
+        let mut request = self.client.get(url).query(params).header("Authorization", auth_header);
+
+        if let Some(entry) = &cached {
+            let age = now.signed_duration_since(entry.cached_at).num_seconds();
+            if age < self.cache_ttl_seconds as i64 {
+                if let Some(etag) = &entry.etag {
+                    request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+                }
+                if let Some(last_modified) = &entry.last_modified {
+                    request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+                }
+            }
+        }
+
+        let response = request.send().await?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(entry) = cached {
+                return Ok(entry.body);
+            }
+        }
+
+        let etag = response.headers().get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok()).map(|s| s.to_string());
+        let last_modified = response.headers().get(reqwest::header::LAST_MODIFIED)
+            .and_then(|v| v.to_str().ok()).map(|s| s.to_string());
+
+        let response = response.error_for_status().context("Calendar API request failed")?;
+        let body: Value = response.json().await?;
+
+        let mut cache = self.cache.lock().unwrap();
+        if cache.len() >= self.cache_max_entries && !cache.contains_key(&key) {
+            if let Some(oldest_key) = cache.iter().min_by_key(|(_, entry)| entry.cached_at).map(|(k, _)| k.clone()) {
+                cache.remove(&oldest_key);
+            }
+        }
+        cache.insert(key, CacheEntry {
+            body: body.clone(),
+            etag,
+            last_modified,
+            cached_at: now,
+        });
+
+        Ok(body)
+    }
+
     pub async fn setup_tools(&mut self) -> Result<()> {
         // Register list_calendars tool
         let list_calendars_tool = Tool {
@@ -168,6 +984,16 @@ impl GoogleCalendarV1MCP {
                         "description": "Include detailed event information",
                         "default": false
                     }));
+                    props.insert("expand_recurrences".to_string(), json!({
+                        "type": "boolean",
+                        "description": "Materialize recurring events (RRULE) into their concrete occurrences within the requested window",
+                        "default": true
+                    }));
+                    props.insert("max_results".to_string(), json!({
+                        "type": "integer",
+                        "description": "Events per API page (1-2500); pagination still follows nextPageToken across pages",
+                        "default": 250
+                    }));
                     props
                 },
                 required: vec![],
@@ -226,274 +1052,1142 @@ impl GoogleCalendarV1MCP {
             },
         };
 
-        self.server.register_tool(list_calendars_tool)?;
-        self.server.register_tool(list_events_tool)?;
-        self.server.register_tool(timezone_tool)?;
-        self.server.register_tool(freebusy_tool)?;
-
-        Ok(())
-    }
-
-    async fn get_api_token(&self) -> Result<String> {
-        // In a real implementation, you'd use a secure credential store
-        env::var("GOOGLE_ACCESS_TOKEN")
-            .context("Google access token not found. Please set GOOGLE_ACCESS_TOKEN environment variable.")
-    }
-
+        // Register create_event tool
+        let create_event_tool = Tool {
+            name: "create_event".to_string(),
+            description: "Creates a new event on the given calendar.".to_string(),
+            input_schema: ToolSchema {
+                schema_type: "object".to_string(),
+                properties: {
+                    let mut props = HashMap::new();
+                    props.insert("calendar_id".to_string(), json!({
+                        "type": "string",
+                        "description": "Calendar ID (default: primary)",
+                        "default": "primary"
+                    }));
+                    props.insert("summary".to_string(), json!({
+                        "type": "string",
+                        "description": "Event title"
+                    }));
+                    props.insert("description".to_string(), json!({
+                        "type": "string",
+                        "description": "Event description (optional)"
+                    }));
+                    props.insert("location".to_string(), json!({
+                        "type": "string",
+                        "description": "Event location (optional)"
+                    }));
+                    props.insert("start".to_string(), json!({
+                        "type": "object",
+                        "description": "Event start. Provide either dateTime (RFC3339) or date (YYYY-MM-DD) for all-day events"
+                    }));
+                    props.insert("end".to_string(), json!({
+                        "type": "object",
+                        "description": "Event end. Provide either dateTime (RFC3339) or date (YYYY-MM-DD) for all-day events"
+                    }));
+                    props.insert("attendees".to_string(), json!({
+                        "type": "array",
+                        "items": {"type": "object"},
+                        "description": "List of attendees with an email field (optional)"
+                    }));
+                    props
+                },
+                required: vec!["summary".to_string(), "start".to_string(), "end".to_string()],
+            },
+        };
+
+        // Register update_event tool
+        let update_event_tool = Tool {
+            name: "update_event".to_string(),
+            description: "Updates fields on an existing event. Only the fields provided are changed.".to_string(),
+            input_schema: ToolSchema {
+                schema_type: "object".to_string(),
+                properties: {
+                    let mut props = HashMap::new();
+                    props.insert("calendar_id".to_string(), json!({
+                        "type": "string",
+                        "description": "Calendar ID (default: primary)",
+                        "default": "primary"
+                    }));
+                    props.insert("event_id".to_string(), json!({
+                        "type": "string",
+                        "description": "ID of the event to update"
+                    }));
+                    props.insert("summary".to_string(), json!({
+                        "type": "string",
+                        "description": "New event title (optional)"
+                    }));
+                    props.insert("description".to_string(), json!({
+                        "type": "string",
+                        "description": "New event description (optional)"
+                    }));
+                    props.insert("location".to_string(), json!({
+                        "type": "string",
+                        "description": "New event location (optional)"
+                    }));
+                    props.insert("start".to_string(), json!({
+                        "type": "object",
+                        "description": "New event start (optional)"
+                    }));
+                    props.insert("end".to_string(), json!({
+                        "type": "object",
+                        "description": "New event end (optional)"
+                    }));
+                    props.insert("attendees".to_string(), json!({
+                        "type": "array",
+                        "items": {"type": "object"},
+                        "description": "New attendee list (optional)"
+                    }));
+                    props
+                },
+                required: vec!["event_id".to_string()],
+            },
+        };
+
+        // Register delete_event tool
+        let delete_event_tool = Tool {
+            name: "delete_event".to_string(),
+            description: "Deletes an event from the given calendar.".to_string(),
+            input_schema: ToolSchema {
+                schema_type: "object".to_string(),
+                properties: {
+                    let mut props = HashMap::new();
+                    props.insert("calendar_id".to_string(), json!({
+                        "type": "string",
+                        "description": "Calendar ID (default: primary)",
+                        "default": "primary"
+                    }));
+                    props.insert("event_id".to_string(), json!({
+                        "type": "string",
+                        "description": "ID of the event to delete"
+                    }));
+                    props
+                },
+                required: vec!["event_id".to_string()],
+            },
+        };
+
+        // Register list_calendar_acl tool
+        let list_acl_tool = Tool {
+            name: "list_calendar_acl".to_string(),
+            description: "Lists the access control rules for a calendar.".to_string(),
+            input_schema: ToolSchema {
+                schema_type: "object".to_string(),
+                properties: {
+                    let mut props = HashMap::new();
+                    props.insert("calendar_id".to_string(), json!({
+                        "type": "string",
+                        "description": "Calendar ID (default: primary)",
+                        "default": "primary"
+                    }));
+                    props
+                },
+                required: vec![],
+            },
+        };
+
+        // Register share_calendar tool
+        let share_calendar_tool = Tool {
+            name: "share_calendar".to_string(),
+            description: "Grants a user access to a calendar by inserting an ACL rule.".to_string(),
+            input_schema: ToolSchema {
+                schema_type: "object".to_string(),
+                properties: {
+                    let mut props = HashMap::new();
+                    props.insert("calendar_id".to_string(), json!({
+                        "type": "string",
+                        "description": "Calendar ID (default: primary)",
+                        "default": "primary"
+                    }));
+                    props.insert("email".to_string(), json!({
+                        "type": "string",
+                        "description": "Email address of the user to share the calendar with"
+                    }));
+                    props.insert("role".to_string(), json!({
+                        "type": "string",
+                        "enum": ["freeBusyReader", "reader", "writer", "owner"],
+                        "description": "Access role to grant",
+                        "default": "reader"
+                    }));
+                    props
+                },
+                required: vec!["email".to_string()],
+            },
+        };
+
+        // Register find_available_slots tool
+        let find_slots_tool = Tool {
+            name: "find_available_slots".to_string(),
+            description: "Finds bookable meeting slots by inverting the free/busy periods across the given calendars. Working hours are interpreted in UTC, not the calendars' local timezone.".to_string(),
+            input_schema: ToolSchema {
+                schema_type: "object".to_string(),
+                properties: {
+                    let mut props = HashMap::new();
+                    props.insert("time_min".to_string(), json!({
+                        "type": "string",
+                        "description": "Lower bound for the search window (RFC3339 timestamp)"
+                    }));
+                    props.insert("time_max".to_string(), json!({
+                        "type": "string",
+                        "description": "Upper bound for the search window (RFC3339 timestamp)"
+                    }));
+                    props.insert("calendar_ids".to_string(), json!({
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Calendars whose busy time should be avoided",
+                        "default": ["primary"]
+                    }));
+                    props.insert("duration_minutes".to_string(), json!({
+                        "type": "integer",
+                        "description": "Length of the meeting slot to find, in minutes"
+                    }));
+                    props.insert("working_hours_start".to_string(), json!({
+                        "type": "string",
+                        "description": "Earliest bookable time of day, UTC (HH:MM)",
+                        "default": "09:00"
+                    }));
+                    props.insert("working_hours_end".to_string(), json!({
+                        "type": "string",
+                        "description": "Latest bookable time of day, UTC (HH:MM)",
+                        "default": "17:00"
+                    }));
+                    props
+                },
+                required: vec!["time_min".to_string(), "time_max".to_string(), "duration_minutes".to_string()],
+            },
+        };
+
+        // Register export_events_ics tool
+        let export_ics_tool = Tool {
+            name: "export_events_ics".to_string(),
+            description: "Exports events from a calendar as an RFC 5545 VCALENDAR/VEVENT (.ics) stream.".to_string(),
+            input_schema: ToolSchema {
+                schema_type: "object".to_string(),
+                properties: {
+                    let mut props = HashMap::new();
+                    props.insert("calendar_id".to_string(), json!({
+                        "type": "string",
+                        "description": "Calendar ID (default: primary)",
+                        "default": "primary"
+                    }));
+                    props.insert("time_min".to_string(), json!({
+                        "type": "string",
+                        "description": "Lower bound for an event's end time (RFC3339 timestamp)"
+                    }));
+                    props.insert("time_max".to_string(), json!({
+                        "type": "string",
+                        "description": "Upper bound for an event's start time (RFC3339 timestamp)"
+                    }));
+                    props
+                },
+                required: vec![],
+            },
+        };
+
+        // Register import_events_ics tool
+        let import_ics_tool = Tool {
+            name: "import_events_ics".to_string(),
+            description: "Parses a pasted ICS (.ics) blob and inserts its VEVENTs into the given calendar.".to_string(),
+            input_schema: ToolSchema {
+                schema_type: "object".to_string(),
+                properties: {
+                    let mut props = HashMap::new();
+                    props.insert("calendar_id".to_string(), json!({
+                        "type": "string",
+                        "description": "Calendar ID (default: primary)",
+                        "default": "primary"
+                    }));
+                    props.insert("ics_content".to_string(), json!({
+                        "type": "string",
+                        "description": "Raw ICS content containing one or more VEVENT blocks"
+                    }));
+                    props
+                },
+                required: vec!["ics_content".to_string()],
+            },
+        };
+
+        // Register watch_calendar tool
+        let watch_calendar_tool = Tool {
+            name: "watch_calendar".to_string(),
+            description: "Registers a push-notification webhook for changes to a calendar's events.".to_string(),
+            input_schema: ToolSchema {
+                schema_type: "object".to_string(),
+                properties: {
+                    let mut props = HashMap::new();
+                    props.insert("calendar_id".to_string(), json!({
+                        "type": "string",
+                        "description": "Calendar ID (default: primary)",
+                        "default": "primary"
+                    }));
+                    props.insert("address".to_string(), json!({
+                        "type": "string",
+                        "description": "HTTPS callback URL that Google will POST change notifications to"
+                    }));
+                    props
+                },
+                required: vec!["address".to_string()],
+            },
+        };
+
+        // Register stop_watch tool
+        let stop_watch_tool = Tool {
+            name: "stop_watch".to_string(),
+            description: "Stops the push-notification channel previously registered for a calendar.".to_string(),
+            input_schema: ToolSchema {
+                schema_type: "object".to_string(),
+                properties: {
+                    let mut props = HashMap::new();
+                    props.insert("calendar_id".to_string(), json!({
+                        "type": "string",
+                        "description": "Calendar ID (default: primary)",
+                        "default": "primary"
+                    }));
+                    props
+                },
+                required: vec![],
+            },
+        };
+
+        // Register sync_changes tool
+        let sync_changes_tool = Tool {
+            name: "sync_changes".to_string(),
+            description: "Fetches only the events changed since the last sync for a calendar, using an incremental sync token. The first call for a calendar (or the first after the token expires) returns all of its events and stores the token.".to_string(),
+            input_schema: ToolSchema {
+                schema_type: "object".to_string(),
+                properties: {
+                    let mut props = HashMap::new();
+                    props.insert("calendar_id".to_string(), json!({
+                        "type": "string",
+                        "description": "Calendar ID (default: primary)",
+                        "default": "primary"
+                    }));
+                    props
+                },
+                required: vec![],
+            },
+        };
+
+        self.server.register_tool(list_calendars_tool)?;
+        self.server.register_tool(list_events_tool)?;
+        self.server.register_tool(timezone_tool)?;
+        self.server.register_tool(freebusy_tool)?;
+        self.server.register_tool(create_event_tool)?;
+        self.server.register_tool(update_event_tool)?;
+        self.server.register_tool(delete_event_tool)?;
+        self.server.register_tool(list_acl_tool)?;
+        self.server.register_tool(find_slots_tool)?;
+        self.server.register_tool(export_ics_tool)?;
+        self.server.register_tool(import_ics_tool)?;
+        self.server.register_tool(watch_calendar_tool)?;
+        self.server.register_tool(stop_watch_tool)?;
+        self.server.register_tool(sync_changes_tool)?;
+        self.server.register_tool(share_calendar_tool)?;
+
+        Ok(())
+    }
+
+    async fn get_api_token(&self) -> Result<String> {
+        // In a real implementation, you'd use a secure credential store
+        env::var("GOOGLE_ACCESS_TOKEN")
+            .context("Google access token not found. Please set GOOGLE_ACCESS_TOKEN environment variable.")
+    }
+
     async fn handle_list_calendars(&self, _args: Value) -> Result<Vec<Content>> {
         let token = self.get_api_token().await?;
         let auth_header = format!("Bearer {}", token);
 
+        let url = format!("{}/users/me/calendarList", self.base_url);
+        let mut calendars: Vec<Calendar> = vec![];
+        let mut page_token: Option<String> = None;
+
+        for _ in 0..MAX_PAGE_FETCHES {
+            let mut params = vec![];
+            if let Some(token) = &page_token {
+                params.push(("pageToken", token.as_str()));
+            }
+
+            let data = self.get_with_cache(&url, &params, &auth_header).await?;
+            let page: CalendarListPage = serde_json::from_value(data)
+                .context("Failed to parse calendar list response")?;
+
+            calendars.extend(page.items.unwrap_or_default());
+
+            match page.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+
+        let mut calendars_text = format!("**Calendars List** ({} found)\n\n", calendars.len());
+
+        for calendar in &calendars {
+            calendars_text.push_str(&format!(
+                "• **{}** (ID: {})\n  - Primary: {}\n  - Access Role: {}\n\n",
+                calendar.summary.as_deref().unwrap_or("Unknown"),
+                calendar.id.as_deref().unwrap_or("Unknown"),
+                calendar.primary.unwrap_or(false),
+                calendar.access_role.as_deref().unwrap_or("Unknown"),
+            ));
+        }
+
+        Ok(vec![Content::Text(TextContent {
+            text: calendars_text,
+        })])
+    }
+
+    async fn handle_list_calendar_events(&self, args: Value) -> Result<Vec<Content>> {
+        let events_args: EventsListArgs = serde_json::from_value(args)
+            .context("Failed to parse events list arguments")?;
+
+        let calendar_id = events_args.calendar_id.unwrap_or_else(|| "primary".to_string());
+        let verbose = events_args.verbose.unwrap_or(false);
+        let max_results = events_args.max_results.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, 2500);
+        let max_results_str = max_results.to_string();
+
+        let token = self.get_api_token().await?;
+        let auth_header = format!("Bearer {}", token);
+
+        let url = format!("{}/calendars/{}/events", self.base_url, calendar_id);
+
+        let mut raw_events: Vec<Value> = vec![];
+        let mut page_token: Option<String> = None;
+
+        for _ in 0..MAX_PAGE_FETCHES {
+            let mut params = vec![("maxResults", max_results_str.as_str())];
+            if let Some(time_max) = &events_args.time_max {
+                params.push(("timeMax", time_max.as_str()));
+            }
+            if let Some(time_min) = &events_args.time_min {
+                params.push(("timeMin", time_min.as_str()));
+            }
+            if let Some(token) = &page_token {
+                params.push(("pageToken", token.as_str()));
+            }
+
+            let data = self.get_with_cache(&url, &params, &auth_header).await?;
+            let page: EventsPage = serde_json::from_value(data)
+                .context("Failed to parse events list response")?;
+
+            raw_events.extend(page.items.unwrap_or_default());
+
+            if raw_events.len() >= MAX_TOTAL_EVENTS {
+                break;
+            }
+            match page.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+
+        let expand_recurrences = events_args.expand_recurrences.unwrap_or(true);
+        let raw_events = if expand_recurrences {
+            expand_recurring_events(&raw_events, events_args.time_min.as_deref(), events_args.time_max.as_deref())
+        } else {
+            raw_events
+        };
+
+        let events: Vec<CalendarEvent> = raw_events.into_iter()
+            .filter_map(|event| serde_json::from_value(event).ok())
+            .collect();
+
+        if events.is_empty() {
+            return Ok(vec![Content::Text(TextContent {
+                text: "No events found for the specified time period.".to_string(),
+            })]);
+        }
+
+        let mut events_text = format!("**Calendar Events** ({} found)\n\n", events.len());
+
+        for event in events.iter().take(20) { // Limit to prevent overwhelming output
+            let summary = event.summary.as_deref().unwrap_or("No title");
+
+            events_text.push_str(&format!("### {}\n", summary));
+
+            if let Some(start) = &event.start {
+                let start_time = start.date_time.as_deref().or(start.date.as_deref()).unwrap_or("Unknown");
+                events_text.push_str(&format!("**Start**: {}\n", start_time));
+            }
+
+            if let Some(end) = &event.end {
+                let end_time = end.date_time.as_deref().or(end.date.as_deref()).unwrap_or("Unknown");
+                events_text.push_str(&format!("**End**: {}\n", end_time));
+            }
+
+            if verbose {
+                // Include all the verbose details that make this painful to use
+                let id = event.id.as_deref().unwrap_or("Unknown");
+                let status = event.status.as_deref().unwrap_or("Unknown");
+                let creator_email = event.creator.as_ref().and_then(|c| c.email.as_deref()).unwrap_or("Unknown");
+                let organizer_email = event.organizer.as_ref().and_then(|o| o.email.as_deref()).unwrap_or("Unknown");
+
+                events_text.push_str(&format!(
+                    "**ID**: {}\n**Status**: {}\n**Creator**: {}\n**Organizer**: {}\n",
+                    id, status, creator_email, organizer_email
+                ));
+
+                if let Some(attendees) = &event.attendees {
+                    events_text.push_str(&format!("**Attendees** ({}):\n", attendees.len()));
+                    for attendee in attendees {
+                        let email = attendee.email.as_deref().unwrap_or("Unknown");
+                        let status = attendee.response_status.as_deref().unwrap_or("Unknown");
+                        events_text.push_str(&format!("  - {} ({})\n", email, status));
+                    }
+                }
+
+                if let Some(description) = &event.description {
+                    events_text.push_str(&format!("**Description**: {}\n", description));
+                }
+
+                if let Some(location) = &event.location {
+                    events_text.push_str(&format!("**Location**: {}\n", location));
+                }
+            }
+
+            events_text.push_str("\n---\n\n");
+        }
+
+        // This response can become extremely long and unwieldy
+        if events_text.len() > 8000 {
+            events_text.truncate(8000);
+            events_text.push_str("\n\n*[Response truncated - too much data]*");
+        }
+
+        Ok(vec![Content::Text(TextContent { text: events_text })])
+    }
+
+    async fn handle_retrieve_timezone(&self, args: Value) -> Result<Vec<Content>> {
+        let timezone_args: TimezoneArgs = serde_json::from_value(args)
+            .context("Failed to parse timezone arguments")?;
+
+        let calendar_id = timezone_args.calendar_id.unwrap_or_else(|| "primary".to_string());
+        
+        let token = self.get_api_token().await?;
+        let auth_header = format!("Bearer {}", token);
+
+        let data = self.get_with_cache(&format!("{}/calendars/{}", self.base_url, calendar_id), &[], &auth_header).await?;
+        let calendar_info: CalendarInfo = serde_json::from_value(data)
+            .context("Failed to parse calendar info response")?;
+
+        let timezone_text = format!(
+            "**Calendar Timezone Information**\n\n**Calendar**: {}\n**Timezone**: {}\n**Location**: {}\n",
+            calendar_info.summary.as_deref().unwrap_or("Unknown"),
+            calendar_info.time_zone.as_deref().unwrap_or("Unknown"),
+            calendar_info.location.as_deref().unwrap_or("Not specified"),
+        );
+
+        Ok(vec![Content::Text(TextContent { text: timezone_text })])
+    }
+
+    async fn handle_retrieve_free_busy_slots(&self, args: Value) -> Result<Vec<Content>> {
+        let freebusy_args: FreeBusyArgs = serde_json::from_value(args)
+            .context("Failed to parse free/busy arguments")?;
+
+        let timezone = freebusy_args.timezone.unwrap_or_else(|| "UTC".to_string());
+        let calendar_ids = freebusy_args.calendar_ids.unwrap_or_else(|| vec!["primary".to_string()]);
+
+        let token = self.get_api_token().await?;
+        let auth_header = format!("Bearer {}", token);
+
+        let payload = FreeBusyRequest {
+            time_min: freebusy_args.time_min.clone(),
+            time_max: freebusy_args.time_max.clone(),
+            time_zone: timezone.clone(),
+            items: calendar_ids.iter().map(|id| FreeBusyRequestItem { id: id.clone() }).collect(),
+        };
+
+        let response = self.client
+            .post(&format!("{}/freeBusy", self.base_url))
+            .header("Authorization", auth_header)
+            .header("Content-Type", "application/json")
+            .json(&payload)
+            .send()
+            .await?;
+
+        let data: Value = response.json().await?;
+
+        // Return raw API response - hard to interpret without additional processing
+        let mut freebusy_text = format!(
+            "**Free/Busy Information**\n\n**Time Range**: {} to {}\n**Timezone**: {}\n\n",
+            freebusy_args.time_min, freebusy_args.time_max, timezone
+        );
+
+        if let Some(calendars) = data.get("calendars").and_then(|v| v.as_object()) {
+            for (calendar_id, calendar_data) in calendars {
+                freebusy_text.push_str(&format!("### Calendar: {}\n", calendar_id));
+
+                if let Some(busy_times) = calendar_data.get("busy").and_then(|v| v.as_array()) {
+                    if !busy_times.is_empty() {
+                        freebusy_text.push_str("**Busy periods**:\n");
+                        for busy in busy_times {
+                            let start = busy.get("start").and_then(|v| v.as_str()).unwrap_or("Unknown");
+                            let end = busy.get("end").and_then(|v| v.as_str()).unwrap_or("Unknown");
+                            freebusy_text.push_str(&format!("  - {} to {}\n", start, end));
+                        }
+                    } else {
+                        freebusy_text.push_str("**No busy periods found**\n");
+                    }
+                }
+
+                if let Some(errors) = calendar_data.get("errors").and_then(|v| v.as_array()) {
+                    if !errors.is_empty() {
+                        freebusy_text.push_str("**Errors**:\n");
+                        for error in errors {
+                            let reason = error.get("reason").and_then(|v| v.as_str()).unwrap_or("Unknown error");
+                            freebusy_text.push_str(&format!("  - {}\n", reason));
+                        }
+                    }
+                }
+
+                freebusy_text.push('\n');
+            }
+        }
+
+        Ok(vec![Content::Text(TextContent { text: freebusy_text })])
+    }
+
+    async fn handle_create_event(&self, args: Value) -> Result<Vec<Content>> {
+        let create_args: CreateEventArgs = serde_json::from_value(args)
+            .context("Failed to parse create event arguments")?;
+
+        let calendar_id = create_args.calendar_id.unwrap_or_else(|| "primary".to_string());
+
+        let token = self.get_api_token().await?;
+        let auth_header = format!("Bearer {}", token);
+
+        let payload = CalendarEvent {
+            id: None,
+            summary: Some(create_args.summary),
+            description: create_args.description,
+            start: Some(create_args.start),
+            end: Some(create_args.end),
+            status: None,
+            creator: None,
+            organizer: None,
+            attendees: create_args.attendees,
+            location: create_args.location,
+            recurrence: None,
+            recurring_event_id: None,
+            original_start_time: None,
+        };
+
+        let response = self.client
+            .post(&format!("{}/calendars/{}/events", self.base_url, calendar_id))
+            .header("Authorization", auth_header)
+            .header("Content-Type", "application/json")
+            .json(&payload)
+            .send()
+            .await?
+            .error_for_status()
+            .context("Failed to create event")?;
+
+        let data: Value = response.json().await?;
+        let id = data.get("id").and_then(|v| v.as_str()).unwrap_or("Unknown");
+        let html_link = data.get("htmlLink").and_then(|v| v.as_str()).unwrap_or("Unknown");
+
+        Ok(vec![Content::Text(TextContent {
+            text: format!(
+                "**Event created**\n\n**Event ID**: {}\n**Link**: {}",
+                id, html_link
+            ),
+        })])
+    }
+
+    async fn handle_update_event(&self, args: Value) -> Result<Vec<Content>> {
+        let update_args: UpdateEventArgs = serde_json::from_value(args)
+            .context("Failed to parse update event arguments")?;
+
+        let calendar_id = update_args.calendar_id.unwrap_or_else(|| "primary".to_string());
+
+        let token = self.get_api_token().await?;
+        let auth_header = format!("Bearer {}", token);
+
+        let mut payload = json!({});
+        if let Some(summary) = &update_args.summary {
+            payload["summary"] = json!(summary);
+        }
+        if let Some(description) = &update_args.description {
+            payload["description"] = json!(description);
+        }
+        if let Some(location) = &update_args.location {
+            payload["location"] = json!(location);
+        }
+        if let Some(start) = &update_args.start {
+            payload["start"] = serde_json::to_value(start)?;
+        }
+        if let Some(end) = &update_args.end {
+            payload["end"] = serde_json::to_value(end)?;
+        }
+        if let Some(attendees) = &update_args.attendees {
+            payload["attendees"] = serde_json::to_value(attendees)?;
+        }
+
+        let response = self.client
+            .patch(&format!("{}/calendars/{}/events/{}", self.base_url, calendar_id, update_args.event_id))
+            .header("Authorization", auth_header)
+            .header("Content-Type", "application/json")
+            .json(&payload)
+            .send()
+            .await?
+            .error_for_status()
+            .context("Failed to update event")?;
+
+        let data: Value = response.json().await?;
+        let id = data.get("id").and_then(|v| v.as_str()).unwrap_or("Unknown");
+        let html_link = data.get("htmlLink").and_then(|v| v.as_str()).unwrap_or("Unknown");
+
+        Ok(vec![Content::Text(TextContent {
+            text: format!(
+                "**Event updated**\n\n**Event ID**: {}\n**Link**: {}",
+                id, html_link
+            ),
+        })])
+    }
+
+    async fn handle_delete_event(&self, args: Value) -> Result<Vec<Content>> {
+        let delete_args: DeleteEventArgs = serde_json::from_value(args)
+            .context("Failed to parse delete event arguments")?;
+
+        let calendar_id = delete_args.calendar_id.unwrap_or_else(|| "primary".to_string());
+
+        let token = self.get_api_token().await?;
+        let auth_header = format!("Bearer {}", token);
+
+        self.client
+            .delete(&format!("{}/calendars/{}/events/{}", self.base_url, calendar_id, delete_args.event_id))
+            .header("Authorization", auth_header)
+            .send()
+            .await?
+            .error_for_status()
+            .context("Failed to delete event")?;
+
+        Ok(vec![Content::Text(TextContent {
+            text: format!("**Event deleted**: {}", delete_args.event_id),
+        })])
+    }
+
+    async fn handle_list_calendar_acl(&self, args: Value) -> Result<Vec<Content>> {
+        let acl_args: ListAclArgs = serde_json::from_value(args)
+            .context("Failed to parse ACL list arguments")?;
+
+        let calendar_id = acl_args.calendar_id.unwrap_or_else(|| "primary".to_string());
+
+        let token = self.get_api_token().await?;
+        let auth_header = format!("Bearer {}", token);
+
         let response = self.client
-            .get(&format!("{}/users/me/calendarList", self.base_url))
+            .get(&format!("{}/calendars/{}/acl", self.base_url, calendar_id))
             .header("Authorization", auth_header)
             .send()
             .await?;
 
         let data: Value = response.json().await?;
+        let rules = data.get("items").and_then(|v| v.as_array()).cloned().unwrap_or_default();
 
-        // Return raw API response as text
-        let mut calendars_text = "**Calendars List**\n\n".to_string();
-        
-        if let Some(items) = data.get("items").and_then(|v| v.as_array()) {
-            for calendar in items {
-                let summary = calendar.get("summary")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Unknown");
-                let id = calendar.get("id")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Unknown");
-                let primary = calendar.get("primary")
-                    .and_then(|v| v.as_bool())
-                    .unwrap_or(false);
-                let access_role = calendar.get("accessRole")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Unknown");
-
-                calendars_text.push_str(&format!(
-                    "• **{}** (ID: {})\n  - Primary: {}\n  - Access Role: {}\n\n",
-                    summary, id, primary, access_role
-                ));
-            }
+        if rules.is_empty() {
+            return Ok(vec![Content::Text(TextContent {
+                text: "No ACL rules found for this calendar.".to_string(),
+            })]);
+        }
+
+        let mut acl_text = format!("**Calendar ACL** ({} found)\n\n", rules.len());
+        for rule in &rules {
+            let role = rule.get("role").and_then(|v| v.as_str()).unwrap_or("Unknown");
+            let scope_type = rule.get("scope").and_then(|s| s.get("type")).and_then(|v| v.as_str()).unwrap_or("Unknown");
+            let scope_value = rule.get("scope").and_then(|s| s.get("value")).and_then(|v| v.as_str()).unwrap_or("Unknown");
+            acl_text.push_str(&format!("• **{}** ({}: {})\n", role, scope_type, scope_value));
         }
 
+        Ok(vec![Content::Text(TextContent { text: acl_text })])
+    }
+
+    async fn handle_share_calendar(&self, args: Value) -> Result<Vec<Content>> {
+        let share_args: ShareCalendarArgs = serde_json::from_value(args)
+            .context("Failed to parse share calendar arguments")?;
+
+        let calendar_id = share_args.calendar_id.unwrap_or_else(|| "primary".to_string());
+        let role = share_args.role.unwrap_or_else(|| "reader".to_string());
+
+        let token = self.get_api_token().await?;
+        let auth_header = format!("Bearer {}", token);
+
+        let payload = AclRule {
+            id: None,
+            role: Some(role.clone()),
+            scope: Some(AclScope {
+                scope_type: Some("user".to_string()),
+                value: Some(share_args.email.clone()),
+            }),
+        };
+
+        let response = self.client
+            .post(&format!("{}/calendars/{}/acl", self.base_url, calendar_id))
+            .header("Authorization", auth_header)
+            .header("Content-Type", "application/json")
+            .json(&payload)
+            .send()
+            .await?
+            .error_for_status()
+            .context("Failed to share calendar")?;
+
+        let data: Value = response.json().await?;
+        let id = data.get("id").and_then(|v| v.as_str()).unwrap_or("Unknown");
+
         Ok(vec![Content::Text(TextContent {
-            text: calendars_text,
+            text: format!(
+                "**Calendar shared**\n\n**Rule ID**: {}\n**Email**: {}\n**Role**: {}",
+                id, share_args.email, role
+            ),
         })])
     }
 
-    async fn handle_list_calendar_events(&self, args: Value) -> Result<Vec<Content>> {
-        let events_args: EventsListArgs = serde_json::from_value(args)
-            .context("Failed to parse events list arguments")?;
+    async fn handle_find_available_slots(&self, args: Value) -> Result<Vec<Content>> {
+        let slot_args: FindSlotsArgs = serde_json::from_value(args)
+            .context("Failed to parse find available slots arguments")?;
+
+        if slot_args.duration_minutes <= 0 {
+            return Err(anyhow::anyhow!("duration_minutes must be a positive number of minutes"));
+        }
+
+        let calendar_ids = slot_args.calendar_ids.clone().unwrap_or_else(|| vec!["primary".to_string()]);
+        let working_hours_start = slot_args.working_hours_start.clone().unwrap_or_else(|| "09:00".to_string());
+        let working_hours_end = slot_args.working_hours_end.clone().unwrap_or_else(|| "17:00".to_string());
 
-        let calendar_id = events_args.calendar_id.unwrap_or_else(|| "primary".to_string());
-        let verbose = events_args.verbose.unwrap_or(false);
-        
         let token = self.get_api_token().await?;
         let auth_header = format!("Bearer {}", token);
 
-        let mut url = format!("{}/calendars/{}/events", self.base_url, calendar_id);
-        let mut params = vec![];
-        
-        if let Some(time_max) = &events_args.time_max {
-            params.push(("timeMax", time_max.as_str()));
-        }
-        if let Some(time_min) = &events_args.time_min {
-            params.push(("timeMin", time_min.as_str()));
-        }
+        // Fetch free/busy exactly as retrieve_calendar_free_busy_slots does.
+        // working_hours_start/end are interpreted as literal UTC clock times
+        // below, so the free/busy query is pinned to UTC too rather than
+        // accepting a timezone that would silently go unused in the clipping.
+        let payload = FreeBusyRequest {
+            time_min: slot_args.time_min.clone(),
+            time_max: slot_args.time_max.clone(),
+            time_zone: "UTC".to_string(),
+            items: calendar_ids.iter().map(|id| FreeBusyRequestItem { id: id.clone() }).collect(),
+        };
 
         let response = self.client
-            .get(&url)
-            .query(&params)
+            .post(&format!("{}/freeBusy", self.base_url))
             .header("Authorization", auth_header)
+            .header("Content-Type", "application/json")
+            .json(&payload)
             .send()
             .await?;
 
         let data: Value = response.json().await?;
 
-        // Return verbose API response - this gets unwieldy quickly
-        let events = data.get("items").and_then(|v| v.as_array()).unwrap_or(&vec![]);
-        
-        if events.is_empty() {
+        let mut busy = vec![];
+        if let Some(calendars) = data.get("calendars").and_then(|v| v.as_object()) {
+            for calendar_data in calendars.values() {
+                if let Some(busy_times) = calendar_data.get("busy").and_then(|v| v.as_array()) {
+                    for period in busy_times {
+                        let start = period.get("start").and_then(|v| v.as_str()).and_then(|s| DateTime::parse_from_rfc3339(s).ok());
+                        let end = period.get("end").and_then(|v| v.as_str()).and_then(|s| DateTime::parse_from_rfc3339(s).ok());
+                        if let (Some(start), Some(end)) = (start, end) {
+                            busy.push((start.with_timezone(&Utc), end.with_timezone(&Utc)));
+                        }
+                    }
+                }
+            }
+        }
+
+        let window_start = DateTime::parse_from_rfc3339(&slot_args.time_min)
+            .context("time_min is not a valid RFC3339 timestamp")?
+            .with_timezone(&Utc);
+        let window_end = DateTime::parse_from_rfc3339(&slot_args.time_max)
+            .context("time_max is not a valid RFC3339 timestamp")?
+            .with_timezone(&Utc);
+        let duration = chrono::Duration::minutes(slot_args.duration_minutes);
+
+        let (work_start_h, work_start_m) = parse_hhmm(&working_hours_start).unwrap_or((9, 0));
+        let (work_end_h, work_end_m) = parse_hhmm(&working_hours_end).unwrap_or((17, 0));
+
+        let merged_busy = merge_intervals(busy);
+
+        // Complement of the merged busy periods within the search window.
+        let mut gaps = vec![];
+        let mut cursor = window_start;
+        for (busy_start, busy_end) in &merged_busy {
+            if *busy_start > cursor {
+                gaps.push((cursor, (*busy_start).min(window_end)));
+            }
+            cursor = cursor.max(*busy_end);
+            if cursor >= window_end {
+                break;
+            }
+        }
+        if cursor < window_end {
+            gaps.push((cursor, window_end));
+        }
+
+        // Clip each gap to working hours per day, then slide the requested
+        // duration across what's left.
+        let mut slots = vec![];
+        for (gap_start, gap_end) in gaps {
+            let mut day = gap_start.date_naive();
+            let last_day = gap_end.date_naive();
+            while day <= last_day {
+                let Some(day_work_start) = day.and_hms_opt(work_start_h, work_start_m, 0)
+                    .map(|n| DateTime::<Utc>::from_naive_utc_and_offset(n, Utc)) else { break };
+                let Some(day_work_end) = day.and_hms_opt(work_end_h, work_end_m, 0)
+                    .map(|n| DateTime::<Utc>::from_naive_utc_and_offset(n, Utc)) else { break };
+
+                let clip_start = gap_start.max(day_work_start);
+                let clip_end = gap_end.min(day_work_end);
+
+                let mut slot_start = clip_start;
+                while slot_start + duration <= clip_end {
+                    slots.push((slot_start, slot_start + duration));
+                    slot_start += duration;
+                }
+
+                let Some(next_day) = day.succ_opt() else { break };
+                day = next_day;
+            }
+        }
+
+        if slots.is_empty() {
             return Ok(vec![Content::Text(TextContent {
-                text: "No events found for the specified time period.".to_string(),
+                text: "No available slots found in the requested window.".to_string(),
             })]);
         }
 
-        let mut events_text = format!("**Calendar Events** ({} found)\n\n", events.len());
+        let mut slots_text = format!("**Available Slots** ({} found, {} min each)\n\n", slots.len(), slot_args.duration_minutes);
+        for (start, end) in &slots {
+            slots_text.push_str(&format!("• {} to {}\n", start.to_rfc3339(), end.to_rfc3339()));
+        }
 
-        for event in events.iter().take(20) { // Limit to prevent overwhelming output
-            let summary = event.get("summary")
-                .and_then(|v| v.as_str())
-                .unwrap_or("No title");
-            
-            events_text.push_str(&format!("### {}\n", summary));
+        Ok(vec![Content::Text(TextContent { text: slots_text })])
+    }
 
-            // Start time
-            if let Some(start) = event.get("start") {
-                let start_time = start.get("dateTime")
-                    .or_else(|| start.get("date"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Unknown");
-                events_text.push_str(&format!("**Start**: {}\n", start_time));
-            }
+    async fn handle_export_events_ics(&self, args: Value) -> Result<Vec<Content>> {
+        let export_args: ExportIcsArgs = serde_json::from_value(args)
+            .context("Failed to parse export ICS arguments")?;
 
-            // End time
-            if let Some(end) = event.get("end") {
-                let end_time = end.get("dateTime")
-                    .or_else(|| end.get("date"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Unknown");
-                events_text.push_str(&format!("**End**: {}\n", end_time));
-            }
+        let calendar_id = export_args.calendar_id.unwrap_or_else(|| "primary".to_string());
 
-            if verbose {
-                // Include all the verbose details that make this painful to use
-                let id = event.get("id").and_then(|v| v.as_str()).unwrap_or("Unknown");
-                let status = event.get("status").and_then(|v| v.as_str()).unwrap_or("Unknown");
-                let creator_email = event.get("creator")
-                    .and_then(|c| c.get("email"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Unknown");
-                let organizer_email = event.get("organizer")
-                    .and_then(|o| o.get("email"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Unknown");
+        let token = self.get_api_token().await?;
+        let auth_header = format!("Bearer {}", token);
 
-                events_text.push_str(&format!(
-                    "**ID**: {}\n**Status**: {}\n**Creator**: {}\n**Organizer**: {}\n",
-                    id, status, creator_email, organizer_email
-                ));
+        let url = format!("{}/calendars/{}/events", self.base_url, calendar_id);
+        let mut params = vec![];
+        if let Some(time_max) = &export_args.time_max {
+            params.push(("timeMax", time_max.as_str()));
+        }
+        if let Some(time_min) = &export_args.time_min {
+            params.push(("timeMin", time_min.as_str()));
+        }
 
-                if let Some(attendees) = event.get("attendees").and_then(|v| v.as_array()) {
-                    events_text.push_str(&format!("**Attendees** ({}):\n", attendees.len()));
-                    for attendee in attendees {
-                        let email = attendee.get("email").and_then(|v| v.as_str()).unwrap_or("Unknown");
-                        let status = attendee.get("responseStatus").and_then(|v| v.as_str()).unwrap_or("Unknown");
-                        events_text.push_str(&format!("  - {} ({})\n", email, status));
-                    }
-                }
+        let data = self.get_with_cache(&url, &params, &auth_header).await?;
+        let events = data.get("items").and_then(|v| v.as_array()).cloned().unwrap_or_default();
 
-                if let Some(description) = event.get("description").and_then(|v| v.as_str()) {
-                    events_text.push_str(&format!("**Description**: {}\n", description));
-                }
+        Ok(vec![Content::Text(TextContent { text: render_ics(&events) })])
+    }
 
-                if let Some(location) = event.get("location").and_then(|v| v.as_str()) {
-                    events_text.push_str(&format!("**Location**: {}\n", location));
-                }
-            }
+    async fn handle_import_events_ics(&self, args: Value) -> Result<Vec<Content>> {
+        let import_args: ImportIcsArgs = serde_json::from_value(args)
+            .context("Failed to parse import ICS arguments")?;
 
-            events_text.push_str("\n---\n\n");
+        let calendar_id = import_args.calendar_id.unwrap_or_else(|| "primary".to_string());
+        let events = parse_ics_events(&import_args.ics_content);
+
+        if events.is_empty() {
+            return Ok(vec![Content::Text(TextContent {
+                text: "No VEVENT entries with both DTSTART and DTEND found in the provided ICS content.".to_string(),
+            })]);
         }
 
-        // This response can become extremely long and unwieldy
-        if events_text.len() > 8000 {
-            events_text.truncate(8000);
-            events_text.push_str("\n\n*[Response truncated - too much data]*");
+        let token = self.get_api_token().await?;
+        let auth_header = format!("Bearer {}", token);
+
+        let mut imported = vec![];
+        for event in &events {
+            let response = self.client
+                .post(&format!("{}/calendars/{}/events", self.base_url, calendar_id))
+                .header("Authorization", auth_header.clone())
+                .header("Content-Type", "application/json")
+                .json(event)
+                .send()
+                .await?
+                .error_for_status()
+                .with_context(|| format!("Failed to import event '{}'", event.summary.as_deref().unwrap_or("No title")))?;
+
+            let data: Value = response.json().await?;
+            let id = data.get("id").and_then(|v| v.as_str()).unwrap_or("Unknown").to_string();
+            imported.push((id, event.summary.clone().unwrap_or_else(|| "No title".to_string())));
         }
 
-        Ok(vec![Content::Text(TextContent { text: events_text })])
+        let mut imported_text = format!("**Imported {} event(s)**\n\n", imported.len());
+        for (id, summary) in &imported {
+            imported_text.push_str(&format!("• **{}** (ID: {})\n", summary, id));
+        }
+
+        Ok(vec![Content::Text(TextContent { text: imported_text })])
     }
 
-    async fn handle_retrieve_timezone(&self, args: Value) -> Result<Vec<Content>> {
-        let timezone_args: TimezoneArgs = serde_json::from_value(args)
-            .context("Failed to parse timezone arguments")?;
+    async fn handle_watch_calendar(&self, args: Value) -> Result<Vec<Content>> {
+        let watch_args: WatchCalendarArgs = serde_json::from_value(args)
+            .context("Failed to parse watch calendar arguments")?;
+
+        let calendar_id = watch_args.calendar_id.unwrap_or_else(|| "primary".to_string());
 
-        let calendar_id = timezone_args.calendar_id.unwrap_or_else(|| "primary".to_string());
-        
         let token = self.get_api_token().await?;
         let auth_header = format!("Bearer {}", token);
 
+        let channel_id = format!("watch-{}-{}", calendar_id, Utc::now().timestamp_nanos_opt().unwrap_or_default());
+
+        let payload = json!({
+            "id": channel_id,
+            "type": "web_hook",
+            "address": watch_args.address,
+        });
+
         let response = self.client
-            .get(&format!("{}/calendars/{}", self.base_url, calendar_id))
+            .post(&format!("{}/calendars/{}/events/watch", self.base_url, calendar_id))
             .header("Authorization", auth_header)
+            .header("Content-Type", "application/json")
+            .json(&payload)
             .send()
-            .await?;
+            .await?
+            .error_for_status()
+            .context("Failed to create watch channel")?;
 
         let data: Value = response.json().await?;
+        let resource_id = data.get("resourceId").and_then(|v| v.as_str()).unwrap_or("").to_string();
+        let returned_channel_id = data.get("id").and_then(|v| v.as_str()).unwrap_or(&channel_id).to_string();
+        let expiration = data.get("expiration").and_then(|v| v.as_str()).map(|s| s.to_string());
+
+        self.watch_channels.lock().unwrap().insert(calendar_id, WatchChannel {
+            channel_id: returned_channel_id.clone(),
+            resource_id: resource_id.clone(),
+            expiration: expiration.clone(),
+        });
 
-        let summary = data.get("summary").and_then(|v| v.as_str()).unwrap_or("Unknown");
-        let timezone = data.get("timeZone").and_then(|v| v.as_str()).unwrap_or("Unknown");
-        let location = data.get("location").and_then(|v| v.as_str()).unwrap_or("Not specified");
+        Ok(vec![Content::Text(TextContent {
+            text: format!(
+                "**Watch channel created**\n\n**Channel ID**: {}\n**Resource ID**: {}\n**Expiration**: {}",
+                returned_channel_id, resource_id, expiration.unwrap_or_else(|| "Unknown".to_string())
+            ),
+        })])
+    }
 
-        let timezone_text = format!(
-            "**Calendar Timezone Information**\n\n**Calendar**: {}\n**Timezone**: {}\n**Location**: {}\n",
-            summary, timezone, location
-        );
+    async fn handle_stop_watch(&self, args: Value) -> Result<Vec<Content>> {
+        let stop_args: StopWatchArgs = serde_json::from_value(args)
+            .context("Failed to parse stop watch arguments")?;
 
-        Ok(vec![Content::Text(TextContent { text: timezone_text })])
-    }
+        let calendar_id = stop_args.calendar_id.unwrap_or_else(|| "primary".to_string());
 
-    async fn handle_retrieve_free_busy_slots(&self, args: Value) -> Result<Vec<Content>> {
-        let freebusy_args: FreeBusyArgs = serde_json::from_value(args)
-            .context("Failed to parse free/busy arguments")?;
+        let channel = self.watch_channels.lock().unwrap().remove(&calendar_id);
 
-        let timezone = freebusy_args.timezone.unwrap_or_else(|| "UTC".to_string());
-        let calendar_ids = freebusy_args.calendar_ids.unwrap_or_else(|| vec!["primary".to_string()]);
+        let Some(channel) = channel else {
+            return Ok(vec![Content::Text(TextContent {
+                text: format!("No active watch channel found for calendar '{}'.", calendar_id),
+            })]);
+        };
 
         let token = self.get_api_token().await?;
         let auth_header = format!("Bearer {}", token);
 
-        let payload = FreeBusyRequest {
-            time_min: freebusy_args.time_min.clone(),
-            time_max: freebusy_args.time_max.clone(),
-            time_zone: timezone.clone(),
-            items: calendar_ids.iter().map(|id| FreeBusyRequestItem { id: id.clone() }).collect(),
-        };
+        let payload = json!({
+            "id": channel.channel_id,
+            "resourceId": channel.resource_id,
+        });
 
-        let response = self.client
-            .post(&format!("{}/freeBusy", self.base_url))
+        self.client
+            .post(&format!("{}/channels/stop", self.base_url))
             .header("Authorization", auth_header)
             .header("Content-Type", "application/json")
             .json(&payload)
             .send()
-            .await?;
+            .await?
+            .error_for_status()
+            .context("Failed to stop watch channel")?;
 
-        let data: Value = response.json().await?;
+        Ok(vec![Content::Text(TextContent {
+            text: format!("**Watch channel stopped**: {}", channel.channel_id),
+        })])
+    }
 
-        // Return raw API response - hard to interpret without additional processing
-        let mut freebusy_text = format!(
-            "**Free/Busy Information**\n\n**Time Range**: {} to {}\n**Timezone**: {}\n\n",
-            freebusy_args.time_min, freebusy_args.time_max, timezone
-        );
+    async fn handle_sync_changes(&self, args: Value) -> Result<Vec<Content>> {
+        let sync_args: SyncChangesArgs = serde_json::from_value(args)
+            .context("Failed to parse sync changes arguments")?;
 
-        if let Some(calendars) = data.get("calendars").and_then(|v| v.as_object()) {
-            for (calendar_id, calendar_data) in calendars {
-                freebusy_text.push_str(&format!("### Calendar: {}\n", calendar_id));
+        let calendar_id = sync_args.calendar_id.unwrap_or_else(|| "primary".to_string());
 
-                if let Some(busy_times) = calendar_data.get("busy").and_then(|v| v.as_array()) {
-                    if !busy_times.is_empty() {
-                        freebusy_text.push_str("**Busy periods**:\n");
-                        for busy in busy_times {
-                            let start = busy.get("start").and_then(|v| v.as_str()).unwrap_or("Unknown");
-                            let end = busy.get("end").and_then(|v| v.as_str()).unwrap_or("Unknown");
-                            freebusy_text.push_str(&format!("  - {} to {}\n", start, end));
-                        }
-                    } else {
-                        freebusy_text.push_str("**No busy periods found**\n");
-                    }
-                }
+        let token = self.get_api_token().await?;
+        let auth_header = format!("Bearer {}", token);
 
-                if let Some(errors) = calendar_data.get("errors").and_then(|v| v.as_array()) {
-                    if !errors.is_empty() {
-                        freebusy_text.push_str("**Errors**:\n");
-                        for error in errors {
-                            let reason = error.get("reason").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-                            freebusy_text.push_str(&format!("  - {}\n", reason));
-                        }
-                    }
-                }
+        let existing_sync_token = self.sync_tokens.lock().unwrap().get(&calendar_id).cloned();
 
-                freebusy_text.push('\n');
+        let url = format!("{}/calendars/{}/events", self.base_url, calendar_id);
+        let mut items: Vec<Value> = vec![];
+        let mut next_sync_token = None;
+        let mut page_token: Option<String> = None;
+
+        // nextSyncToken only appears on the final page of a sync; earlier
+        // pages return nextPageToken, so the full page set must be walked
+        // before the sync token can be trusted.
+        for _ in 0..MAX_PAGE_FETCHES {
+            let mut params = vec![];
+            if let Some(sync_token) = &existing_sync_token {
+                params.push(("syncToken", sync_token.as_str()));
+            }
+            if let Some(token) = &page_token {
+                params.push(("pageToken", token.as_str()));
+            }
+
+            let response = self.client
+                .get(&url)
+                .query(&params)
+                .header("Authorization", auth_header.clone())
+                .send()
+                .await?;
+
+            if response.status() == reqwest::StatusCode::GONE {
+                // Sync token expired or is invalid; clear it so the next call does a full resync.
+                self.sync_tokens.lock().unwrap().remove(&calendar_id);
+                return Ok(vec![Content::Text(TextContent {
+                    text: "Sync token expired (410 Gone). Cleared the stored token \u{2014} call sync_changes again to perform a full resync.".to_string(),
+                })]);
+            }
+            let response = response.error_for_status().context("Failed to fetch calendar changes")?;
+
+            let data: Value = response.json().await?;
+            items.extend(data.get("items").and_then(|v| v.as_array()).cloned().unwrap_or_default());
+            if let Some(token) = data.get("nextSyncToken").and_then(|v| v.as_str()) {
+                next_sync_token = Some(token.to_string());
+            }
+
+            match data.get("nextPageToken").and_then(|v| v.as_str()) {
+                Some(token) => page_token = Some(token.to_string()),
+                None => break,
             }
         }
 
-        Ok(vec![Content::Text(TextContent { text: freebusy_text })])
+        if let Some(next_sync_token) = next_sync_token {
+            self.sync_tokens.lock().unwrap().insert(calendar_id, next_sync_token);
+        }
+
+        if items.is_empty() {
+            return Ok(vec![Content::Text(TextContent {
+                text: "No changes since last sync.".to_string(),
+            })]);
+        }
+
+        let mut changes_text = format!("**Changed Events** ({} since last sync)\n\n", items.len());
+        for item in &items {
+            let summary = item.get("summary").and_then(|v| v.as_str()).unwrap_or("No title");
+            let status = item.get("status").and_then(|v| v.as_str()).unwrap_or("confirmed");
+            changes_text.push_str(&format!("• **{}** ({})\n", summary, status));
+        }
+
+        Ok(vec![Content::Text(TextContent { text: changes_text })])
     }
 
     pub async fn run(&mut self) -> Result<()> {
         self.setup_tools().await?;
-        
+
         self.server.set_tool_handler("list_calendars", |args| async move {
             self.handle_list_calendars(args).await
         });
@@ -510,6 +2204,50 @@ impl GoogleCalendarV1MCP {
             self.handle_retrieve_free_busy_slots(args).await
         });
 
+        self.server.set_tool_handler("create_event", |args| async move {
+            self.handle_create_event(args).await
+        });
+
+        self.server.set_tool_handler("update_event", |args| async move {
+            self.handle_update_event(args).await
+        });
+
+        self.server.set_tool_handler("delete_event", |args| async move {
+            self.handle_delete_event(args).await
+        });
+
+        self.server.set_tool_handler("list_calendar_acl", |args| async move {
+            self.handle_list_calendar_acl(args).await
+        });
+
+        self.server.set_tool_handler("share_calendar", |args| async move {
+            self.handle_share_calendar(args).await
+        });
+
+        self.server.set_tool_handler("find_available_slots", |args| async move {
+            self.handle_find_available_slots(args).await
+        });
+
+        self.server.set_tool_handler("export_events_ics", |args| async move {
+            self.handle_export_events_ics(args).await
+        });
+
+        self.server.set_tool_handler("import_events_ics", |args| async move {
+            self.handle_import_events_ics(args).await
+        });
+
+        self.server.set_tool_handler("watch_calendar", |args| async move {
+            self.handle_watch_calendar(args).await
+        });
+
+        self.server.set_tool_handler("stop_watch", |args| async move {
+            self.handle_stop_watch(args).await
+        });
+
+        self.server.set_tool_handler("sync_changes", |args| async move {
+            self.handle_sync_changes(args).await
+        });
+
         println!("Google Calendar v1 MCP Server (Rust) running on stdio");
         self.server.run().await?;
         Ok(())